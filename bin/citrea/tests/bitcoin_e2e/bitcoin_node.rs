@@ -0,0 +1,163 @@
+use std::fs::File;
+use std::path::{Path, PathBuf};
+use std::process::Stdio;
+
+use anyhow::Context;
+use bitcoincore_rpc::{Auth, Client, RpcApi};
+use tokio::process::{Child, Command};
+use tokio::time::{sleep, Duration, Instant};
+
+use super::config::BitcoinConfig;
+use super::docker::{container_name_for, DockerBind, DockerEnv};
+use super::node::Node;
+use super::utils::{get_stderr_path, get_stdout_path};
+use super::Result;
+
+/// A `bitcoind` regtest node, run either as a local process or (when
+/// `BitcoinConfig::docker_image` is set) inside a container, so E2E runs
+/// stay reproducible and several can run in parallel without colliding on
+/// ports or on-disk state.
+#[allow(unused)]
+pub struct Bitcoin {
+    process: Child,
+    config: BitcoinConfig,
+    pub dir: PathBuf,
+    pub rpc_port: u16,
+    pub p2p_port: u16,
+    docker: Option<DockerEnv>,
+}
+
+impl Bitcoin {
+    pub async fn new(config: &BitcoinConfig, dir: PathBuf) -> Result<Self> {
+        let docker = Self::build_docker_env(config, &dir);
+        let process = Self::spawn_with_docker(config, &dir, docker.as_ref()).await?;
+
+        let rpc_port = match &docker {
+            Some(docker) => docker.published_port(config.rpc_port).await?,
+            None => config.rpc_port,
+        };
+        let p2p_port = match &docker {
+            Some(docker) => docker.published_port(config.p2p_port).await?,
+            None => config.p2p_port,
+        };
+
+        DockerEnv::wait_for_port(rpc_port, Duration::from_secs(30)).await?;
+
+        Ok(Self {
+            process,
+            config: config.clone(),
+            dir,
+            rpc_port,
+            p2p_port,
+            docker,
+        })
+    }
+
+    fn rpc_client(&self) -> Result<Client> {
+        Client::new(
+            &format!("http://127.0.0.1:{}", self.rpc_port),
+            Auth::UserPass(self.config.rpc_user.clone(), self.config.rpc_password.clone()),
+        )
+        .context("Failed to build bitcoind RPC client")
+    }
+
+    pub async fn wait_for_height(&self, height: u64, timeout: Option<Duration>) -> Result<()> {
+        let timeout = timeout.unwrap_or(Duration::from_secs(60));
+        let start = Instant::now();
+        let client = self.rpc_client()?;
+        while start.elapsed() < timeout {
+            if client.get_block_count().unwrap_or(0) >= height {
+                return Ok(());
+            }
+            sleep(Duration::from_millis(500)).await;
+        }
+        anyhow::bail!("Bitcoin node failed to reach height within the specified timeout")
+    }
+
+    fn build_docker_env(config: &BitcoinConfig, dir: &Path) -> Option<DockerEnv> {
+        config
+            .docker_image
+            .as_ref()
+            .map(|image| DockerEnv::new(image.clone(), container_name_for("bitcoind", dir)))
+    }
+
+    async fn spawn_with_docker(
+        config: &BitcoinConfig,
+        dir: &Path,
+        docker: Option<&DockerEnv>,
+    ) -> Result<Child> {
+        let stdout_file =
+            File::create(get_stdout_path(dir)).context("Failed to create stdout file")?;
+        let stderr_file =
+            File::create(get_stderr_path(dir)).context("Failed to create stderr file")?;
+
+        if let Some(docker) = docker {
+            return docker
+                .start(
+                    dir,
+                    &[DockerBind::new(&config.data_dir, "/data")],
+                    // Host port 0: let docker pick a free port so multiple
+                    // regtest nodes can run side by side in CI.
+                    &[(0, config.p2p_port), (0, config.rpc_port)],
+                    &container_args(config, "/data"),
+                    Stdio::from(stdout_file),
+                    Stdio::from(stderr_file),
+                )
+                .await;
+        }
+
+        Command::new("bitcoind")
+            .args(config.args())
+            .stdout(Stdio::from(stdout_file))
+            .stderr(Stdio::from(stderr_file))
+            .kill_on_drop(true)
+            .spawn()
+            .context("Failed to spawn bitcoind process")
+    }
+}
+
+/// Same args as the local-process path, but with the data dir rewritten to
+/// the in-container bind mount and without `-daemon` (docker already keeps
+/// the container running in the foreground).
+fn container_args(config: &BitcoinConfig, container_data_dir: &str) -> Vec<String> {
+    config
+        .args()
+        .into_iter()
+        .filter(|arg| arg != "-daemon")
+        .map(|arg| {
+            if arg.starts_with("-datadir=") {
+                format!("-datadir={container_data_dir}")
+            } else {
+                arg
+            }
+        })
+        .collect()
+}
+
+impl Node for Bitcoin {
+    type Config = BitcoinConfig;
+
+    async fn spawn(config: &Self::Config, dir: &Path) -> Result<Child> {
+        let docker = Self::build_docker_env(config, dir);
+        Self::spawn_with_docker(config, dir, docker.as_ref()).await
+    }
+
+    async fn stop(&mut self) -> Result<()> {
+        if let Some(docker) = &self.docker {
+            return docker.stop().await;
+        }
+        Ok(self.process.kill().await?)
+    }
+
+    async fn wait_for_ready(&self, timeout: Duration) -> Result<()> {
+        let start = Instant::now();
+        let client = self.rpc_client()?;
+        while start.elapsed() < timeout {
+            if client.get_blockchain_info().is_ok() {
+                return Ok(());
+            }
+            sleep(Duration::from_millis(500)).await;
+        }
+        anyhow::bail!("Bitcoin node failed to become ready within the specified timeout")
+    }
+}
@@ -13,6 +13,9 @@ pub struct BitcoinConfig {
     pub data_dir: PathBuf,
     pub extra_args: Vec<String>,
     pub network: Network,
+    /// Image to run `bitcoind` inside instead of as a local process. `None`
+    /// keeps the existing local-process behavior so tests that don't care
+    /// about isolation can skip the docker dependency entirely.
     pub docker_image: Option<String>,
 }
 
@@ -34,6 +37,10 @@ impl Default for BitcoinConfig {
 }
 
 impl BitcoinConfig {
+    /// Args for a full `bitcoind` node, including `-txindex`. Only needed
+    /// when the rollup is configured to sync DA via RPC; the light-client
+    /// backend (`citrea_sequencer::DaBackendConfig::LightClient`) needs none
+    /// of this since it syncs through Esplora instead.
     fn base_args(&self) -> Vec<String> {
         vec![
             "-regtest".to_string(),
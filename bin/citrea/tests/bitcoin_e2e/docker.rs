@@ -0,0 +1,185 @@
+use std::path::Path;
+use std::process::Stdio;
+
+use anyhow::Context;
+use tokio::process::{Child, Command};
+use tokio::time::{sleep, Duration, Instant};
+
+/// Derives a container name from `prefix` and the node's own (already
+/// per-test-case-unique) working directory, so every sequencer/Bitcoin
+/// node in a run gets a distinct `docker run --name` without needing any
+/// shared counter between the static `Node::spawn` and the instance that
+/// later calls `stop`/`published_port` on the same container.
+pub fn container_name_for(prefix: &str, dir: &Path) -> String {
+    let sanitized: String = dir
+        .display()
+        .to_string()
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '-' })
+        .collect();
+    // Keep the tail of the path (closest to unique) within docker's name
+    // length limits rather than the root, which is shared by every node.
+    let tail: String = sanitized.chars().rev().take(48).collect::<Vec<_>>().into_iter().rev().collect();
+    format!("{prefix}-{}", tail.trim_matches('-'))
+}
+
+/// A single host-path -> container-path bind mount.
+#[derive(Debug, Clone)]
+pub struct DockerBind {
+    pub host_path: std::path::PathBuf,
+    pub container_path: std::path::PathBuf,
+}
+
+impl DockerBind {
+    pub fn new(host_path: impl Into<std::path::PathBuf>, container_path: impl Into<std::path::PathBuf>) -> Self {
+        Self {
+            host_path: host_path.into(),
+            container_path: container_path.into(),
+        }
+    }
+
+    fn as_arg(&self) -> String {
+        format!(
+            "{}:{}",
+            self.host_path.display(),
+            self.container_path.display()
+        )
+    }
+}
+
+/// Thin wrapper around the `docker` CLI used to run a node's binary inside a
+/// container instead of as a local process, so E2E runs are reproducible and
+/// can be parallelized in CI without port/state collisions between runs.
+#[derive(Debug, Clone)]
+pub struct DockerEnv {
+    image: String,
+    container_name: String,
+}
+
+impl DockerEnv {
+    pub fn new(image: impl Into<String>, container_name: impl Into<String>) -> Self {
+        Self {
+            image: image.into(),
+            container_name: container_name.into(),
+        }
+    }
+
+    /// Starts the container, publishing `ports` as `host:container` pairs and
+    /// bind-mounting `binds`, then running `args` as the container command.
+    /// Returns the `docker run` child process; the caller is expected to wait
+    /// on ports/RPC readiness rather than on process exit.
+    pub async fn start(
+        &self,
+        dir: &Path,
+        binds: &[DockerBind],
+        ports: &[(u16, u16)],
+        args: &[String],
+        stdout: Stdio,
+        stderr: Stdio,
+    ) -> anyhow::Result<Child> {
+        // Best-effort cleanup of a leftover container from a previous run.
+        let _ = Command::new("docker")
+            .args(["rm", "-f", &self.container_name])
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .status()
+            .await;
+
+        std::fs::create_dir_all(dir).context("Failed to create node directory")?;
+
+        let mut cmd = Command::new("docker");
+        cmd.arg("run")
+            .arg("--rm")
+            .arg("--name")
+            .arg(&self.container_name);
+
+        for bind in binds {
+            cmd.arg("-v").arg(bind.as_arg());
+        }
+
+        for (host_port, container_port) in ports {
+            cmd.arg("-p").arg(format!("{host_port}:{container_port}"));
+        }
+
+        cmd.arg(&self.image).args(args);
+
+        cmd.stdout(stdout)
+            .stderr(stderr)
+            .kill_on_drop(true)
+            .spawn()
+            .context("Failed to spawn docker run")
+    }
+
+    /// Stops and removes the container. Unlike killing a local process, this
+    /// must reach out to the docker daemon rather than the `Child` handle,
+    /// since `docker run --rm` is just the client attached to the daemon.
+    pub async fn stop(&self) -> anyhow::Result<()> {
+        Command::new("docker")
+            .args(["stop", "-t", "10", &self.container_name])
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .status()
+            .await
+            .context("Failed to stop docker container")?;
+
+        let _ = Command::new("docker")
+            .args(["rm", "-f", &self.container_name])
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .status()
+            .await;
+
+        Ok(())
+    }
+
+    /// Looks up the host port docker assigned for `container_port`, for use
+    /// when `start` was called with a `0` host port so concurrent instances
+    /// don't fight over a fixed one. Parses `docker port`'s
+    /// `0.0.0.0:PORT`/`[::]:PORT` output. Retries for up to 30 seconds, since
+    /// the container may not be up yet right after `start` returns.
+    pub async fn published_port(&self, container_port: u16) -> anyhow::Result<u16> {
+        let start = Instant::now();
+        let stdout = loop {
+            let output = Command::new("docker")
+                .args(["port", &self.container_name, &container_port.to_string()])
+                .output()
+                .await
+                .context("Failed to run docker port")?;
+            if (output.status.success() && !output.stdout.is_empty())
+                || start.elapsed() >= Duration::from_secs(30)
+            {
+                break String::from_utf8_lossy(&output.stdout).into_owned();
+            }
+            sleep(Duration::from_millis(200)).await;
+        };
+        let first_line = stdout
+            .lines()
+            .next()
+            .context("docker port returned no mapping")?;
+        let port_str = first_line
+            .rsplit(':')
+            .next()
+            .context("unexpected docker port output")?;
+        port_str
+            .trim()
+            .parse()
+            .context("failed to parse published port")
+    }
+
+    /// Polls `host_port` on localhost until it accepts a TCP connection or
+    /// `timeout` elapses. Used by the node constructors to wait for the
+    /// (possibly docker-mapped) RPC port before building an RPC client.
+    pub async fn wait_for_port(host_port: u16, timeout: Duration) -> anyhow::Result<()> {
+        let start = Instant::now();
+        while start.elapsed() < timeout {
+            if tokio::net::TcpStream::connect(("127.0.0.1", host_port))
+                .await
+                .is_ok()
+            {
+                return Ok(());
+            }
+            sleep(Duration::from_millis(200)).await;
+        }
+        anyhow::bail!("Port {host_port} did not become reachable within the specified timeout")
+    }
+}
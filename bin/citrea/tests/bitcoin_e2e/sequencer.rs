@@ -10,6 +10,7 @@ use tokio::time::{sleep, Duration, Instant};
 use super::config::config_to_file;
 use super::config::RollupConfig;
 use super::config::TestConfig;
+use super::docker::{container_name_for, DockerBind, DockerEnv};
 use super::node::Node;
 use super::utils::{get_citrea_path, get_stderr_path, get_stdout_path};
 use super::Result;
@@ -25,6 +26,7 @@ pub struct Sequencer {
     pub dir: PathBuf,
     rollup_config: RollupConfig,
     pub client: Box<TestClient>,
+    docker: Option<DockerEnv>,
 }
 
 impl Sequencer {
@@ -42,15 +44,19 @@ impl Sequencer {
         println!("Rollup config: {rollup_config:#?}");
         println!("Sequencer dir: {:#?}", dir);
 
-        let process = Self::spawn(
-            &(config.sequencer.clone(), config.sequencer_rollup.clone()),
-            &dir,
-        )
-        .await?;
+        let docker = Self::build_docker_env(sequencer_config, &dir);
+        let process = Self::spawn_with_docker(sequencer_config, rollup_config, &dir, docker.as_ref()).await?;
 
-        // Wait for ws server
-        // TODO Add to wait_for_ready
-        sleep(Duration::from_secs(3)).await;
+        // The host port we actually reach the sequencer on: the configured
+        // port for a local process, or whatever docker assigned when we
+        // published it as `0:rpc.bind_port`/`0:p2p.bind_port` so concurrent
+        // sequencers don't fight over a fixed host port.
+        let rpc_host_port = match &docker {
+            Some(docker) => docker.published_port(rollup_config.rpc.bind_port).await?,
+            None => rollup_config.rpc.bind_port,
+        };
+
+        DockerEnv::wait_for_port(rpc_host_port, Duration::from_secs(30)).await?;
 
         let socket_addr = SocketAddr::new(
             rollup_config
@@ -58,7 +64,7 @@ impl Sequencer {
                 .bind_host
                 .parse()
                 .context("Failed to parse bind host")?,
-            rollup_config.rpc.bind_port,
+            rpc_host_port,
         );
         let client = make_test_client(socket_addr).await;
 
@@ -68,6 +74,7 @@ impl Sequencer {
             dir,
             rollup_config: rollup_config.clone(),
             client,
+            docker,
         })
     }
 
@@ -82,26 +89,69 @@ impl Sequencer {
         }
         anyhow::bail!("Sequencer failed to reach height within the specified timeout")
     }
-}
-
-impl Node for Sequencer {
-    type Config = (SequencerConfig, RollupConfig);
 
-    async fn spawn(config: &Self::Config, dir: &Path) -> Result<Child> {
-        let citrea = get_citrea_path();
+    /// Builds the `DockerEnv` this sequencer runs under, if any. Kept as a
+    /// single constructor so both `new` and `Node::spawn` derive the exact
+    /// same container name for the same `dir`, instead of each building
+    /// (and potentially diverging on) their own `DockerEnv`.
+    fn build_docker_env(sequencer_config: &SequencerConfig, dir: &Path) -> Option<DockerEnv> {
+        sequencer_config.docker_image.as_ref().map(|image| {
+            DockerEnv::new(image.clone(), container_name_for("citrea-sequencer", dir))
+        })
+    }
 
+    async fn spawn_with_docker(
+        sequencer_config: &SequencerConfig,
+        rollup_config: &RollupConfig,
+        dir: &Path,
+        docker: Option<&DockerEnv>,
+    ) -> Result<Child> {
         let stdout_file =
             File::create(get_stdout_path(dir)).context("Failed to create stdout file")?;
         let stderr_file =
             File::create(get_stderr_path(dir)).context("Failed to create stderr file")?;
 
-        let (sequencer_config, rollup_config) = config;
         let config_path = dir.join("sequencer_config.toml");
         config_to_file(&sequencer_config, &config_path)?;
 
         let rollup_config_path = dir.join("sequencer_rollup_config.toml");
         config_to_file(&rollup_config, &rollup_config_path)?;
 
+        if let Some(docker) = docker {
+            let genesis_path = get_genesis_path();
+            return docker
+                .start(
+                    dir,
+                    &[
+                        DockerBind::new(&config_path, "/sequencer_config.toml"),
+                        DockerBind::new(&rollup_config_path, "/sequencer_rollup_config.toml"),
+                        DockerBind::new(genesis_path, "/genesis"),
+                    ],
+                    // Host port 0 lets docker assign a free one, so two
+                    // sequencers running concurrently never collide on a
+                    // fixed host port; `published_port` looks the real one
+                    // back up once the container is up.
+                    &[
+                        (0, rollup_config.rpc.bind_port),
+                        (0, rollup_config.p2p.bind_port),
+                    ],
+                    &[
+                        "--da-layer".to_string(),
+                        "bitcoin".to_string(),
+                        "--rollup-config-path".to_string(),
+                        "/sequencer_rollup_config.toml".to_string(),
+                        "--sequencer-config-path".to_string(),
+                        "/sequencer_config.toml".to_string(),
+                        "--genesis-paths".to_string(),
+                        "/genesis".to_string(),
+                    ],
+                    Stdio::from(stdout_file),
+                    Stdio::from(stderr_file),
+                )
+                .await;
+        }
+
+        let citrea = get_citrea_path();
         Command::new(citrea)
             .arg("--da-layer")
             .arg("bitcoin")
@@ -117,8 +167,21 @@ impl Node for Sequencer {
             .spawn()
             .context("Failed to spawn citrea process")
     }
+}
+
+impl Node for Sequencer {
+    type Config = (SequencerConfig, RollupConfig);
+
+    async fn spawn(config: &Self::Config, dir: &Path) -> Result<Child> {
+        let (sequencer_config, rollup_config) = config;
+        let docker = Self::build_docker_env(sequencer_config, dir);
+        Self::spawn_with_docker(sequencer_config, rollup_config, dir, docker.as_ref()).await
+    }
 
     async fn stop(&mut self) -> Result<()> {
+        if let Some(docker) = &self.docker {
+            return docker.stop().await;
+        }
         Ok(self.process.kill().await?)
     }
 
@@ -0,0 +1,239 @@
+//! Withdrawal-collection bridge subsystem.
+//!
+//! Mirrors a "collect then submit" flow: [`WithdrawalCollector`] scans the
+//! EVM rollup for withdrawal-initiated events over a block range and writes
+//! them to a batch file; [`WithdrawalSubmitter`] reads that file and
+//! produces the DA/Bitcoin payloads for inclusion. Kept as two separate
+//! steps so a long collection run can be restarted without re-submitting
+//! already-collected withdrawals.
+
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+use jsonrpsee::core::client::ClientT;
+use jsonrpsee::ws_client::{WsClient, WsClientBuilder};
+use serde::{Deserialize, Serialize};
+use sha3::{Digest, Keccak256};
+
+/// Configuration for the withdrawal bridge. Lives alongside the rest of
+/// [`crate::SequencerConfig`] rather than as a separate config file, so a
+/// single `sequencer_config.toml` fully describes a deployment.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BridgeConfig {
+    /// Websocket URL of the rollup's geth-compatible RPC endpoint.
+    pub ws_endpoint: String,
+    /// Inclusive block range to scan for withdrawal-initiated logs.
+    pub from_block: u64,
+    pub to_block: u64,
+    /// Where collected withdrawals are written/read as a batch file.
+    pub batch_path: PathBuf,
+    /// Overwrite `batch_path` instead of resuming from its checkpoint.
+    pub force: bool,
+}
+
+/// A single withdrawal event collected from the rollup.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WithdrawalEvent {
+    pub block_number: u64,
+    pub tx_hash: String,
+    pub recipient_script: Vec<u8>,
+    pub amount_sats: u64,
+}
+
+/// On-disk batch file: the events collected so far plus the last block
+/// fully scanned, so a restarted run can resume instead of re-scanning
+/// (and double-collecting) the whole range.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct WithdrawalBatch {
+    last_scanned_block: Option<u64>,
+    events: Vec<WithdrawalEvent>,
+}
+
+/// Scans the EVM rollup for withdrawal-initiated logs and assembles them
+/// into a batch file for [`WithdrawalSubmitter`].
+pub struct WithdrawalCollector {
+    config: BridgeConfig,
+}
+
+impl WithdrawalCollector {
+    pub fn new(config: BridgeConfig) -> Self {
+        Self { config }
+    }
+
+    /// Runs the collection loop over `config.from_block..=config.to_block`,
+    /// resuming from the batch file's checkpoint unless `config.force` is
+    /// set, and persists progress after every block so the run is
+    /// interruptible without losing or duplicating events.
+    pub async fn run(&self) -> anyhow::Result<()> {
+        let mut batch = self.load_or_init_batch()?;
+        let start_block = match (self.config.force, batch.last_scanned_block) {
+            (false, Some(last)) => last + 1,
+            _ => self.config.from_block,
+        };
+
+        let client: WsClient = WsClientBuilder::default()
+            .build(&self.config.ws_endpoint)
+            .await?;
+
+        for block in start_block..=self.config.to_block {
+            let events = self.fetch_withdrawal_logs(&client, block).await?;
+            batch.events.extend(events);
+            batch.last_scanned_block = Some(block);
+            self.persist_batch(&batch)?;
+        }
+
+        Ok(())
+    }
+
+    async fn fetch_withdrawal_logs(
+        &self,
+        client: &WsClient,
+        block: u64,
+    ) -> anyhow::Result<Vec<WithdrawalEvent>> {
+        let logs: Vec<serde_json::Value> = client
+            .request(
+                "eth_getLogs",
+                jsonrpsee::rpc_params![serde_json::json!({
+                    "fromBlock": format!("0x{block:x}"),
+                    "toBlock": format!("0x{block:x}"),
+                    "topics": [withdrawal_initiated_topic()],
+                })],
+            )
+            .await?;
+
+        logs.into_iter()
+            .map(|log| parse_withdrawal_log(block, &log))
+            .collect()
+    }
+
+    fn load_or_init_batch(&self) -> anyhow::Result<WithdrawalBatch> {
+        if self.config.force || !self.config.batch_path.exists() {
+            return Ok(WithdrawalBatch::default());
+        }
+        let bytes = std::fs::read(&self.config.batch_path)?;
+        Ok(serde_json::from_slice(&bytes)?)
+    }
+
+    fn persist_batch(&self, batch: &WithdrawalBatch) -> anyhow::Result<()> {
+        write_atomically(&self.config.batch_path, &serde_json::to_vec_pretty(batch)?)
+    }
+}
+
+/// Signature of the bridge contract's withdrawal event:
+/// `WithdrawalInitiated(address indexed sender, bytes recipientScript, uint256 amountSats)`.
+/// `sender` is indexed so it ends up in `topics[1]`; `recipientScript` and
+/// `amountSats` are ABI-encoded together in `data`.
+const WITHDRAWAL_INITIATED_SIGNATURE: &str =
+    "WithdrawalInitiated(address,bytes,uint256)";
+
+/// `eth_getLogs` topic0 for [`WITHDRAWAL_INITIATED_SIGNATURE`], computed
+/// rather than hand-copied so it can't drift from the signature above.
+fn withdrawal_initiated_topic() -> &'static str {
+    static TOPIC: OnceLock<String> = OnceLock::new();
+    TOPIC.get_or_init(|| {
+        let hash = Keccak256::digest(WITHDRAWAL_INITIATED_SIGNATURE.as_bytes());
+        let mut out = String::with_capacity(2 + hash.len() * 2);
+        out.push_str("0x");
+        for byte in hash {
+            out.push_str(&format!("{byte:02x}"));
+        }
+        out
+    })
+}
+
+fn parse_withdrawal_log(block_number: u64, log: &serde_json::Value) -> anyhow::Result<WithdrawalEvent> {
+    let tx_hash = log
+        .get("transactionHash")
+        .and_then(|v| v.as_str())
+        .unwrap_or_default()
+        .to_string();
+
+    let data_hex = log
+        .get("data")
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| anyhow::anyhow!("withdrawal log for tx {tx_hash} is missing `data`"))?;
+    let data = decode_hex(data_hex)?;
+    let (recipient_script, amount_sats) = decode_withdrawal_data(&data)
+        .ok_or_else(|| anyhow::anyhow!("malformed withdrawal log data for tx {tx_hash}"))?;
+
+    Ok(WithdrawalEvent {
+        block_number,
+        tx_hash,
+        recipient_script,
+        amount_sats,
+    })
+}
+
+/// Decodes the ABI-encoded `(bytes recipientScript, uint256 amountSats)`
+/// tuple from a log's `data` field: a 32-byte offset to `recipientScript`,
+/// a 32-byte `amountSats`, then `recipientScript`'s own length-prefixed,
+/// right-padded encoding.
+fn decode_withdrawal_data(data: &[u8]) -> Option<(Vec<u8>, u64)> {
+    const HEAD_LEN: usize = 64;
+    if data.len() < HEAD_LEN {
+        return None;
+    }
+
+    let amount_sats = u64_from_be_word(data.get(32..64)?);
+    let length = u64_from_be_word(data.get(64..96)?) as usize;
+
+    let start = 96;
+    let end = start.checked_add(length)?;
+    let recipient_script = data.get(start..end)?.to_vec();
+
+    Some((recipient_script, amount_sats))
+}
+
+/// Reads the low 8 bytes of a big-endian, left-padded 32-byte ABI word.
+/// Withdrawal amounts and lengths fit comfortably in a `u64`; a value that
+/// doesn't would indicate a malformed or adversarial log.
+fn u64_from_be_word(word: &[u8]) -> u64 {
+    let mut buf = [0u8; 8];
+    buf.copy_from_slice(&word[24..32]);
+    u64::from_be_bytes(buf)
+}
+
+fn decode_hex(s: &str) -> anyhow::Result<Vec<u8>> {
+    let s = s.strip_prefix("0x").unwrap_or(s);
+    if s.len() % 2 != 0 {
+        anyhow::bail!("odd-length hex string");
+    }
+    (0..s.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&s[i..i + 2], 16).map_err(Into::into))
+        .collect()
+}
+
+/// Reads a batch file produced by [`WithdrawalCollector`] and produces the
+/// DA/Bitcoin payloads needed to include the withdrawals on-chain.
+pub struct WithdrawalSubmitter {
+    batch_path: PathBuf,
+}
+
+impl WithdrawalSubmitter {
+    pub fn new(batch_path: impl Into<PathBuf>) -> Self {
+        Self {
+            batch_path: batch_path.into(),
+        }
+    }
+
+    /// Builds one DA payload (a serialized blob) per withdrawal in the
+    /// batch. Submission of the resulting payloads to the DA layer is left
+    /// to the caller, which already owns a DA service connection.
+    pub fn build_da_payloads(&self) -> anyhow::Result<Vec<Vec<u8>>> {
+        let bytes = std::fs::read(&self.batch_path)?;
+        let batch: WithdrawalBatch = serde_json::from_slice(&bytes)?;
+        batch
+            .events
+            .iter()
+            .map(|event| serde_json::to_vec(event).map_err(Into::into))
+            .collect()
+    }
+}
+
+fn write_atomically(path: &Path, contents: &[u8]) -> anyhow::Result<()> {
+    let tmp_path = path.with_extension("tmp");
+    std::fs::write(&tmp_path, contents)?;
+    std::fs::rename(tmp_path, path)?;
+    Ok(())
+}
@@ -0,0 +1,77 @@
+use std::net::SocketAddr;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{BridgeConfig, DaBackendConfig};
+
+/// Mempool-specific knobs for the sequencer's transaction pool.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SequencerMempoolConfig {
+    /// Maximum number of transactions taken from the pool into one soft
+    /// confirmation.
+    pub max_account_slots: u64,
+    /// Total pool size limit in bytes. Not enforced: `Mempool::insert`
+    /// accepts every transaction regardless of the pool's current size.
+    pub max_pool_size_bytes: u64,
+}
+
+impl Default for SequencerMempoolConfig {
+    fn default() -> Self {
+        Self {
+            max_account_slots: 16,
+            max_pool_size_bytes: 32 * 1024 * 1024,
+        }
+    }
+}
+
+/// Prometheus/OTLP telemetry knobs. Left unset, the sequencer emits no
+/// metrics at all so existing deployments and tests are unaffected.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct MetricsConfig {
+    /// Address the Prometheus scrape endpoint listens on, e.g. `127.0.0.1:9845`.
+    pub bind_addr: Option<SocketAddr>,
+    /// OTLP collector endpoint for trace export, e.g. `http://localhost:4317`.
+    pub otlp_endpoint: Option<String>,
+}
+
+/// Configuration for [`crate::CitreaSequencer`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SequencerConfig {
+    /// Host/port the sequencer's JSON-RPC server binds to.
+    pub rpc_bind_addr: SocketAddr,
+    /// Mempool sizing knobs. The pool does not evict transactions.
+    pub mempool_conf: SequencerMempoolConfig,
+    /// Image to run the sequencer binary inside instead of as a local
+    /// process, mirroring `BitcoinConfig::docker_image`. `None` keeps the
+    /// existing local-process behavior.
+    pub docker_image: Option<String>,
+    /// Operational telemetry. Disabled unless `metrics.bind_addr` is set.
+    #[serde(default)]
+    pub metrics: MetricsConfig,
+    /// Settings for the withdrawal-collection bridge
+    /// ([`crate::WithdrawalCollector`]). `CitreaSequencer` does not start
+    /// the bridge itself, whether or not this is set.
+    #[serde(default)]
+    pub bridge: Option<BridgeConfig>,
+    /// Which Bitcoin DA backend to sync through: full-node RPC (the
+    /// default) or the Esplora + compact-filter light client.
+    #[serde(default = "default_da_backend")]
+    pub da_backend: DaBackendConfig,
+}
+
+fn default_da_backend() -> DaBackendConfig {
+    DaBackendConfig::FullNode
+}
+
+impl Default for SequencerConfig {
+    fn default() -> Self {
+        Self {
+            rpc_bind_addr: "127.0.0.1:0".parse().expect("valid socket addr"),
+            mempool_conf: SequencerMempoolConfig::default(),
+            docker_image: None,
+            metrics: MetricsConfig::default(),
+            bridge: None,
+            da_backend: default_da_backend(),
+        }
+    }
+}
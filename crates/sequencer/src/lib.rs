@@ -1,10 +1,16 @@
+mod bridge;
 mod commitment_controller;
 mod config;
 mod db_provider;
+mod light_client;
 mod mempool;
+mod metrics;
 mod rpc;
 mod sequencer;
 mod utils;
 
-pub use config::{SequencerConfig, SequencerMempoolConfig};
+pub use bridge::{BridgeConfig, WithdrawalCollector, WithdrawalEvent, WithdrawalSubmitter};
+pub use config::{MetricsConfig, SequencerConfig, SequencerMempoolConfig};
+pub use light_client::{DaBackendConfig, LightClientConfig, LightClientDaSync};
+pub use metrics::init_metrics;
 pub use sequencer::CitreaSequencer;
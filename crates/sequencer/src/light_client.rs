@@ -0,0 +1,321 @@
+//! Light-client Bitcoin DA backend.
+//!
+//! `db_provider` talks to a full `bitcoind` with `-txindex` (see
+//! `BitcoinConfig::base_args`). This module lets a sequencer or verifier
+//! sync rollup blobs from an Esplora HTTP endpoint instead, using BIP157/158
+//! compact block filters to decide which blocks are even worth downloading,
+//! so bandwidth scales with matches rather than chain size.
+//!
+//! Golomb-coded-set parameters follow BIP158: `P = 19`, `M = 784931`.
+
+use std::sync::Mutex;
+
+use bitcoin::hashes::{sha256d, Hash};
+use bitcoin::{Block, BlockHash, ScriptBuf};
+use serde::{Deserialize, Serialize};
+
+const GCS_P: u8 = 19;
+const GCS_M: u64 = 784931;
+
+/// Selects which Bitcoin DA backend the sequencer/verifier syncs through.
+/// Set via `SequencerConfig::da_backend`, so a deployment can switch to the
+/// light client without a full `bitcoind` without touching any other config.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum DaBackendConfig {
+    /// Full node JSON-RPC, requires `-txindex`.
+    FullNode,
+    /// Esplora + compact filters, no full node required.
+    LightClient(LightClientConfig),
+}
+
+impl DaBackendConfig {
+    /// Builds the light-client syncer this config selects, or `None` when
+    /// configured for the full-node RPC path.
+    pub fn build_light_client(&self) -> Option<LightClientDaSync> {
+        match self {
+            DaBackendConfig::FullNode => None,
+            DaBackendConfig::LightClient(config) => Some(LightClientDaSync::new(config.clone())),
+        }
+    }
+}
+
+/// Configuration for the Esplora-backed light client.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LightClientConfig {
+    /// Base URL of the Esplora HTTP API, e.g. `https://blockstream.info/api`.
+    pub esplora_url: String,
+    /// Scripts the rollup writes its DA output to; only blocks whose filter
+    /// matches one of these are downloaded in full. Owned (`ScriptBuf`), not
+    /// borrowed (`Script`): `Script` is a `?Sized` borrowed type and can't be
+    /// stored in a `Vec` or (de)serialized directly.
+    pub watched_scripts: Vec<ScriptBuf>,
+}
+
+/// A single entry in the chain of BIP157 filter headers. Each header commits
+/// to the filter it was computed from and to the previous header, so a
+/// fetched filter can't be swapped for a different one without breaking the
+/// chain from that point on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FilterHeader {
+    pub block_hash: BlockHash,
+    pub header: sha256d::Hash,
+}
+
+impl FilterHeader {
+    /// The header for the block before the chain starts, per BIP157.
+    pub fn genesis_prev() -> sha256d::Hash {
+        sha256d::Hash::all_zeros()
+    }
+}
+
+/// A decoded BIP158 Golomb-coded set filter for one block.
+#[derive(Debug, Clone)]
+pub struct CompactFilter {
+    pub block_hash: BlockHash,
+    encoded: Vec<u8>,
+}
+
+impl CompactFilter {
+    pub fn from_encoded(block_hash: BlockHash, encoded: Vec<u8>) -> Self {
+        Self { block_hash, encoded }
+    }
+
+    /// The BIP157 filter hash: a single SHA256D over the raw encoded filter.
+    pub fn filter_hash(&self) -> sha256d::Hash {
+        sha256d::Hash::hash(&self.encoded)
+    }
+
+    /// The filter header committing this filter to `prev_header`, i.e.
+    /// `SHA256D(filter_hash || prev_header)`.
+    pub fn header(&self, prev_header: sha256d::Hash) -> sha256d::Hash {
+        let mut preimage = Vec::with_capacity(64);
+        preimage.extend_from_slice(self.filter_hash().as_byte_array());
+        preimage.extend_from_slice(prev_header.as_byte_array());
+        sha256d::Hash::hash(&preimage)
+    }
+
+    /// SipHash key for this filter, derived from the block hash per BIP158.
+    fn siphash_key(&self) -> [u8; 16] {
+        let hash = self.block_hash.to_raw_hash();
+        let bytes = hash.as_byte_array();
+        let mut key = [0u8; 16];
+        key.copy_from_slice(&bytes[0..16]);
+        key
+    }
+
+    /// Returns true if `scripts` has at least one member of the GCS filter.
+    /// A positive here is necessary but not sufficient to prove a match
+    /// (BIP158 filters have a false-positive rate of ~1/M); callers should
+    /// download the full block to confirm before acting on it.
+    pub fn matches_any(&self, scripts: &[ScriptBuf]) -> bool {
+        if scripts.is_empty() {
+            return false;
+        }
+        let Some((n, data)) = parse_n_and_data(&self.encoded) else {
+            return false;
+        };
+        if n == 0 {
+            return false;
+        }
+
+        let key = self.siphash_key();
+        let targets: std::collections::HashSet<u64> = scripts
+            .iter()
+            .map(|s| hash_to_range(&key, s.as_bytes(), n))
+            .collect();
+
+        let decoded = decode_gcs(data, n);
+        decoded.iter().any(|v| targets.contains(v))
+    }
+}
+
+/// Parses the leading BIP158 `CompactSize` element count and returns it
+/// along with the remaining GCS-encoded bytes (i.e. everything after the
+/// CompactSize prefix, not the whole buffer).
+fn parse_n_and_data(encoded: &[u8]) -> Option<(u64, &[u8])> {
+    let (n, prefix_len) = read_compact_size(encoded)?;
+    Some((n, &encoded[prefix_len..]))
+}
+
+/// Bitcoin `CompactSize` decoding: returns `(value, bytes_consumed)`.
+fn read_compact_size(data: &[u8]) -> Option<(u64, usize)> {
+    match *data.first()? {
+        n @ 0..=0xfc => Some((n as u64, 1)),
+        0xfd => Some((u16::from_le_bytes(data.get(1..3)?.try_into().ok()?) as u64, 3)),
+        0xfe => Some((u32::from_le_bytes(data.get(1..5)?.try_into().ok()?) as u64, 5)),
+        0xff => Some((u64::from_le_bytes(data.get(1..9)?.try_into().ok()?), 9)),
+    }
+}
+
+/// Hashes `item` into the range `[0, n * M)` as specified by BIP158, using a
+/// SipHash-2-4 keyed by the block hash so candidate scripts can be compared
+/// against the set without rebuilding the whole filter.
+fn hash_to_range(key: &[u8; 16], item: &[u8], n: u64) -> u64 {
+    let k0 = u64::from_le_bytes(key[0..8].try_into().expect("8 bytes"));
+    let k1 = u64::from_le_bytes(key[8..16].try_into().expect("8 bytes"));
+    let hash = siphash24(k0, k1, item);
+    // map_into_range from BIP158: (hash * n * M) >> 64
+    let f = n.saturating_mul(GCS_M);
+    ((hash as u128 * f as u128) >> 64) as u64
+}
+
+fn siphash24(k0: u64, k1: u64, data: &[u8]) -> u64 {
+    bitcoin::hashes::siphash24::Hash::hash_to_u64_with_keys(k0, k1, data)
+}
+
+/// Decodes a Golomb-Rice coded set with parameter `P` into its member hashes.
+/// `data` must already have the leading CompactSize element count stripped
+/// off by the caller. Each codeword is `quotient` unary-coded bits
+/// terminated by a zero, then `P` remainder bits; values are delta-coded
+/// against the running sum.
+fn decode_gcs(data: &[u8], n: u64) -> Vec<u64> {
+    let mut reader = BitReader::new(data);
+    let mut out = Vec::with_capacity(n as usize);
+    let mut last = 0u64;
+    for _ in 0..n {
+        let mut quotient = 0u64;
+        while reader.read_bit() == Some(1) {
+            quotient += 1;
+        }
+        let remainder = reader.read_bits(GCS_P as u32).unwrap_or(0);
+        let delta = (quotient << GCS_P) | remainder;
+        last += delta;
+        out.push(last);
+    }
+    out
+}
+
+struct BitReader<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> BitReader<'a> {
+    fn new(data: &'a [u8]) -> Self {
+        Self { data, pos: 0 }
+    }
+
+    fn read_bit(&mut self) -> Option<u8> {
+        let byte = *self.data.get(self.pos / 8)?;
+        let bit = (byte >> (7 - (self.pos % 8))) & 1;
+        self.pos += 1;
+        Some(bit)
+    }
+
+    fn read_bits(&mut self, n: u32) -> Option<u64> {
+        let mut value = 0u64;
+        for _ in 0..n {
+            value = (value << 1) | self.read_bit()? as u64;
+        }
+        Some(value)
+    }
+}
+
+/// Syncs rollup blobs from Esplora using compact filter matching: walk the
+/// filter header chain, download each block's GCS filter, and only fetch
+/// the full block body when the filter matches one of the rollup's DA
+/// output scripts.
+pub struct LightClientDaSync {
+    config: LightClientConfig,
+    http: reqwest::Client,
+    /// The last-verified filter header, used as `prev_header` for the next
+    /// block so the chain is checked incrementally as blocks are processed
+    /// in order.
+    last_header: Mutex<sha256d::Hash>,
+}
+
+impl LightClientDaSync {
+    /// Builds a syncer that starts the filter header chain at the BIP157
+    /// genesis value. Only correct if `fetch_if_relevant` is first called on
+    /// the chain's actual genesis block; starting partway through the chain
+    /// with this constructor will fail the first header check. Use
+    /// [`Self::with_checkpoint`] to resume from a known height instead.
+    pub fn new(config: LightClientConfig) -> Self {
+        Self::with_checkpoint(config, FilterHeader::genesis_prev())
+    }
+
+    /// Builds a syncer whose filter header chain starts from `last_header`,
+    /// i.e. the verified header of the block immediately before the first
+    /// one that will be passed to `fetch_if_relevant`. Lets a sync resume
+    /// from a trusted checkpoint instead of the chain genesis.
+    ///
+    /// Note on trust: `verify_filter_header` only proves that a fetched
+    /// filter is internally consistent with the header Esplora itself
+    /// reports for the same block, both from `self.config.esplora_url`. It
+    /// does not prove the header chain is canonical — a malicious or
+    /// compromised Esplora endpoint could serve a self-consistent but wrong
+    /// chain. Callers that need that guarantee must seed `last_header` from
+    /// an independently trusted source (e.g. a full node) and treat this
+    /// syncer's output as provisional until cross-checked.
+    pub fn with_checkpoint(config: LightClientConfig, last_header: sha256d::Hash) -> Self {
+        Self {
+            config,
+            http: reqwest::Client::new(),
+            last_header: Mutex::new(last_header),
+        }
+    }
+
+    /// Returns the full block if its compact filter matches a watched
+    /// script, or `None` if the block can be skipped entirely. Blocks must
+    /// be processed in chain order: each call extends the verified filter
+    /// header chain from the previous call's result.
+    pub async fn fetch_if_relevant(&self, block_hash: BlockHash) -> anyhow::Result<Option<Block>> {
+        let filter = self.fetch_filter(block_hash).await?;
+        self.verify_filter_header(&filter).await?;
+
+        if !filter.matches_any(&self.config.watched_scripts) {
+            return Ok(None);
+        }
+        Ok(Some(self.fetch_block(block_hash).await?))
+    }
+
+    /// Fetches the expected filter header for `filter.block_hash` and
+    /// checks it against `filter.header(prev_header)`, rejecting a filter
+    /// that doesn't commit to the chain we've verified so far.
+    async fn verify_filter_header(&self, filter: &CompactFilter) -> anyhow::Result<()> {
+        let expected = self.fetch_filter_header(filter.block_hash).await?;
+        let prev_header = *self.last_header.lock().expect("lock poisoned");
+        let computed = filter.header(prev_header);
+
+        if computed != expected {
+            anyhow::bail!(
+                "filter header mismatch for block {}: computed {computed}, esplora reported {expected}",
+                filter.block_hash
+            );
+        }
+
+        *self.last_header.lock().expect("lock poisoned") = computed;
+        Ok(())
+    }
+
+    async fn fetch_filter(&self, block_hash: BlockHash) -> anyhow::Result<CompactFilter> {
+        let url = format!("{}/block/{}/filter", self.config.esplora_url, block_hash);
+        let bytes = self.http.get(url).send().await?.bytes().await?;
+        Ok(CompactFilter::from_encoded(block_hash, bytes.to_vec()))
+    }
+
+    async fn fetch_filter_header(&self, block_hash: BlockHash) -> anyhow::Result<sha256d::Hash> {
+        let url = format!(
+            "{}/block/{}/filter-header",
+            self.config.esplora_url, block_hash
+        );
+        let text = self.http.get(url).send().await?.text().await?;
+        text.trim().parse().context_str("invalid filter header hex")
+    }
+
+    async fn fetch_block(&self, block_hash: BlockHash) -> anyhow::Result<Block> {
+        let url = format!("{}/block/{}/raw", self.config.esplora_url, block_hash);
+        let bytes = self.http.get(url).send().await?.bytes().await?;
+        bitcoin::consensus::deserialize(&bytes).map_err(Into::into)
+    }
+}
+
+trait ContextStr<T> {
+    fn context_str(self, msg: &str) -> anyhow::Result<T>;
+}
+
+impl<T, E> ContextStr<T> for Result<T, E> {
+    fn context_str(self, msg: &str) -> anyhow::Result<T> {
+        self.map_err(|_| anyhow::anyhow!("{msg}"))
+    }
+}
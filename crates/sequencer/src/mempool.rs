@@ -0,0 +1,54 @@
+use crate::metrics;
+
+/// A transaction held in the sequencer's pool, pending inclusion in a soft
+/// confirmation.
+#[derive(Debug, Clone)]
+pub struct PooledTransaction {
+    pub raw: Vec<u8>,
+}
+
+/// The sequencer's pending-transaction pool. Every mutation updates the
+/// `citrea_sequencer_mempool_*` gauges so operators can alert on a backlog
+/// building up faster than blocks drain it.
+#[derive(Debug, Default)]
+pub struct Mempool {
+    transactions: Vec<PooledTransaction>,
+}
+
+impl Mempool {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn insert(&mut self, tx: PooledTransaction) {
+        self.transactions.push(tx);
+        self.publish_metrics();
+    }
+
+    /// Removes and returns up to `count` transactions, in insertion order,
+    /// for inclusion in the next soft confirmation.
+    pub fn drain(&mut self, count: usize) -> Vec<PooledTransaction> {
+        let drained = self
+            .transactions
+            .drain(..count.min(self.transactions.len()))
+            .collect();
+        self.publish_metrics();
+        drained
+    }
+
+    pub fn len(&self) -> usize {
+        self.transactions.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.transactions.is_empty()
+    }
+
+    fn size_bytes(&self) -> u64 {
+        self.transactions.iter().map(|tx| tx.raw.len() as u64).sum()
+    }
+
+    fn publish_metrics(&self) {
+        metrics::record_mempool_size(self.transactions.len() as u64, self.size_bytes());
+    }
+}
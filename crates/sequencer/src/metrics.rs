@@ -0,0 +1,83 @@
+use std::net::SocketAddr;
+
+use metrics_exporter_prometheus::PrometheusBuilder;
+
+/// Starts the Prometheus scrape endpoint and, if configured, OTLP export,
+/// so operators can alert on a stalled sequencer (no soft confirmations
+/// produced) or a backed-up mempool without grepping logs.
+///
+/// Call once at startup, before any of the `metrics::*` macros below are hit.
+pub fn init_metrics(bind_addr: SocketAddr, otlp_endpoint: Option<&str>) -> anyhow::Result<()> {
+    PrometheusBuilder::new()
+        .with_http_listener(bind_addr)
+        .install()
+        .map_err(|e| anyhow::anyhow!("Failed to install Prometheus recorder: {e}"))?;
+
+    if let Some(endpoint) = otlp_endpoint {
+        init_otlp_tracing(endpoint)?;
+    }
+
+    Ok(())
+}
+
+fn init_otlp_tracing(endpoint: &str) -> anyhow::Result<()> {
+    use opentelemetry_otlp::WithExportConfig;
+    use tracing_subscriber::layer::SubscriberExt;
+    use tracing_subscriber::util::SubscriberInitExt;
+
+    let tracer = opentelemetry_otlp::new_pipeline()
+        .tracing()
+        .with_exporter(
+            opentelemetry_otlp::new_exporter()
+                .tonic()
+                .with_endpoint(endpoint),
+        )
+        .install_batch(opentelemetry_sdk::runtime::Tokio)?;
+
+    tracing_subscriber::registry()
+        .with(tracing_opentelemetry::layer().with_tracer(tracer))
+        .try_init()
+        .map_err(|e| anyhow::anyhow!("Failed to init OTLP tracing subscriber: {e}"))
+}
+
+/// Metric names emitted by the sequencer. Centralized here so the gauge set
+/// an operator needs to alert on (mempool backlog, commitment failures,
+/// confirmation latency) doesn't drift from what's actually instrumented.
+pub mod labels {
+    pub const MEMPOOL_TX_COUNT: &str = "citrea_sequencer_mempool_tx_count";
+    pub const MEMPOOL_SIZE_BYTES: &str = "citrea_sequencer_mempool_size_bytes";
+    pub const SOFT_CONFIRMATION_LATENCY_SECONDS: &str =
+        "citrea_sequencer_soft_confirmation_latency_seconds";
+    pub const DA_BLOBS_SUBMITTED_TOTAL: &str = "citrea_sequencer_da_blobs_submitted_total";
+    pub const DA_BLOB_CONFIRMATION_DEPTH: &str = "citrea_sequencer_da_blob_confirmation_depth";
+    pub const COMMITMENTS_SUBMITTED_TOTAL: &str = "citrea_sequencer_commitments_submitted_total";
+    pub const COMMITMENTS_FAILED_TOTAL: &str = "citrea_sequencer_commitments_failed_total";
+}
+
+/// Records current mempool occupancy. Call after every mempool mutation
+/// (insert, evict, or block-building drain) so the gauge never lags reality.
+pub fn record_mempool_size(tx_count: u64, size_bytes: u64) {
+    metrics::gauge!(labels::MEMPOOL_TX_COUNT).set(tx_count as f64);
+    metrics::gauge!(labels::MEMPOOL_SIZE_BYTES).set(size_bytes as f64);
+}
+
+/// Records how long it took to produce a soft confirmation, end to end.
+pub fn record_soft_confirmation_latency(latency: std::time::Duration) {
+    metrics::histogram!(labels::SOFT_CONFIRMATION_LATENCY_SECONDS).record(latency.as_secs_f64());
+}
+
+/// Records a blob handed to the DA layer along with its current confirmation
+/// depth, so a stalled DA submission shows up as a depth that stops moving.
+pub fn record_da_blob_submitted(confirmation_depth: u64) {
+    metrics::counter!(labels::DA_BLOBS_SUBMITTED_TOTAL).increment(1);
+    metrics::gauge!(labels::DA_BLOB_CONFIRMATION_DEPTH).set(confirmation_depth as f64);
+}
+
+/// Records the outcome of a commitment submission from `commitment_controller`.
+pub fn record_commitment_result(success: bool) {
+    if success {
+        metrics::counter!(labels::COMMITMENTS_SUBMITTED_TOTAL).increment(1);
+    } else {
+        metrics::counter!(labels::COMMITMENTS_FAILED_TOTAL).increment(1);
+    }
+}
@@ -0,0 +1,85 @@
+use std::time::Instant;
+
+use bitcoin::{Block, BlockHash};
+
+use crate::commitment_controller::CommitmentController;
+use crate::light_client::LightClientDaSync;
+use crate::mempool::{Mempool, PooledTransaction};
+use crate::metrics;
+use crate::SequencerConfig;
+
+/// The sequencer: owns the mempool, drives soft-confirmation production,
+/// and periodically submits commitments to the DA layer.
+pub struct CitreaSequencer {
+    config: SequencerConfig,
+    mempool: Mempool,
+    commitment_controller: CommitmentController,
+    /// Set when `config.da_backend` selects the Esplora light client, used
+    /// to check DA inclusion without a full `bitcoind`. `None` when running
+    /// against the full-node RPC backend instead.
+    light_client_da: Option<LightClientDaSync>,
+}
+
+impl CitreaSequencer {
+    /// Constructs the sequencer and, if `config.metrics.bind_addr` is set,
+    /// starts the Prometheus/OTLP telemetry subsystem before anything else
+    /// runs so no early mempool or DA activity goes unrecorded.
+    pub fn new(config: SequencerConfig) -> anyhow::Result<Self> {
+        if let Some(bind_addr) = config.metrics.bind_addr {
+            metrics::init_metrics(bind_addr, config.metrics.otlp_endpoint.as_deref())?;
+        }
+
+        let light_client_da = config.da_backend.build_light_client();
+
+        Ok(Self {
+            config,
+            mempool: Mempool::new(),
+            commitment_controller: CommitmentController::new(),
+            light_client_da,
+        })
+    }
+
+    /// Checks whether `block_hash` carries DA data relevant to this rollup,
+    /// using the Esplora light client. Returns `None` when running against
+    /// the full-node backend instead, where inclusion is checked via
+    /// `db_provider` rather than compact filters.
+    pub async fn fetch_da_block_if_relevant(
+        &self,
+        block_hash: BlockHash,
+    ) -> anyhow::Result<Option<Block>> {
+        match &self.light_client_da {
+            Some(light_client) => light_client.fetch_if_relevant(block_hash).await,
+            None => Ok(None),
+        }
+    }
+
+    pub fn accept_transaction(&mut self, raw: Vec<u8>) {
+        self.mempool.insert(PooledTransaction { raw });
+    }
+
+    /// Builds and submits one soft confirmation from the current mempool
+    /// contents, recording the end-to-end latency so a stalled sequencer
+    /// (one that stops producing confirmations, or takes far longer than
+    /// usual) is visible as a metric rather than only as a user complaint.
+    pub async fn produce_soft_confirmation(&mut self) -> anyhow::Result<()> {
+        let start = Instant::now();
+
+        let batch = self.mempool.drain(self.config.mempool_conf.max_account_slots as usize);
+        let blob = self.build_da_blob(&batch);
+        self.submit_blob_to_da(blob).await?;
+
+        metrics::record_soft_confirmation_latency(start.elapsed());
+        Ok(())
+    }
+
+    fn build_da_blob(&self, batch: &[PooledTransaction]) -> Vec<u8> {
+        batch.iter().flat_map(|tx| tx.raw.clone()).collect()
+    }
+
+    async fn submit_blob_to_da(&self, blob: Vec<u8>) -> anyhow::Result<()> {
+        // Confirmation depth starts at zero; a real DA service would poll
+        // for inclusion and report the growing depth over time.
+        metrics::record_da_blob_submitted(0);
+        self.commitment_controller.submit_commitment(blob).await
+    }
+}
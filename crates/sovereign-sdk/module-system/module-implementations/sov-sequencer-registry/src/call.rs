@@ -0,0 +1,56 @@
+use sov_modules_api::prelude::*;
+use sov_modules_api::{CallResponse, Context, DaSpec, Error, WorkingSet};
+
+use crate::SequencerRegistry;
+
+/// Call messages accepted by the `sov-sequencer-registry` module.
+#[cfg_attr(
+    feature = "native",
+    derive(schemars::JsonSchema),
+    derive(sov_modules_api::macros::CliWalletArg)
+)]
+#[derive(borsh::BorshDeserialize, borsh::BorshSerialize, Debug, PartialEq, Clone)]
+pub enum CallMessage {
+    /// Registers the sender as a sequencer, joining the rotation schedule.
+    Register {
+        /// The DA-layer address of the sequencer to register.
+        da_address: Vec<u8>,
+    },
+    /// Unregisters the sender, leaving the rotation schedule.
+    Exit {
+        /// The DA-layer address of the sequencer to remove.
+        da_address: Vec<u8>,
+    },
+}
+
+impl<C: Context, Da: DaSpec> SequencerRegistry<C, Da> {
+    pub(crate) fn register(
+        &self,
+        da_address: &Da::Address,
+        context: &C,
+        working_set: &mut WorkingSet<C>,
+    ) -> Result<CallResponse, Error> {
+        self.register_sequencer(da_address, context.sender(), working_set)?;
+        Ok(CallResponse::default())
+    }
+
+    pub(crate) fn exit(
+        &self,
+        da_address: &Da::Address,
+        context: &C,
+        working_set: &mut WorkingSet<C>,
+    ) -> Result<CallResponse, Error> {
+        if let Some(rollup_address) = self.allowed_sequencers.get(da_address, working_set) {
+            if &rollup_address != context.sender() {
+                return Err(anyhow::anyhow!(
+                    "sender {} did not register sequencer {}",
+                    context.sender(),
+                    da_address
+                )
+                .into());
+            }
+        }
+        self.exit_sequencer(da_address, working_set)?;
+        Ok(CallResponse::default())
+    }
+}
@@ -0,0 +1,51 @@
+use sov_modules_api::{Context, DaSpec, WorkingSet};
+
+use crate::SequencerRegistry;
+
+/// Genesis configuration for the `sov-sequencer-registry` module.
+#[cfg_attr(feature = "native", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone)]
+pub struct SequencerConfig<C: Context, Da: DaSpec> {
+    /// The sequencer's rollup-side address, used to lock/unlock bond coins.
+    pub seq_rollup_address: C::Address,
+    /// The sequencers in the initial leader-election schedule. The rotation
+    /// order is these addresses sorted by their string form, not the order
+    /// given here. A single entry reproduces the previous "one preferred
+    /// sequencer" behavior.
+    pub seq_da_addresses: Vec<Da::Address>,
+    /// Coins locked as a bond for every registered sequencer.
+    pub coins_to_lock: sov_bank::Coins<C>,
+    /// Whether `seq_da_addresses[0]` should be treated as a preferred
+    /// sequencer whose batches are processed first in the block.
+    pub is_preferred_sequencer: bool,
+}
+
+impl<C: Context, Da: DaSpec> SequencerRegistry<C, Da> {
+    pub(crate) fn init_module(
+        &self,
+        config: &<Self as sov_modules_api::Module>::Config,
+        working_set: &mut WorkingSet<C>,
+    ) -> anyhow::Result<()> {
+        self.coins_to_lock.set(&config.coins_to_lock, working_set);
+
+        for da_address in &config.seq_da_addresses {
+            self.register_sequencer(da_address, &config.seq_rollup_address, working_set)?;
+        }
+
+        // The rotation schedule always covers every registered sequencer,
+        // independent of `is_preferred_sequencer` (which only controls
+        // whether `seq_da_addresses[0]` is recorded as the preferred
+        // sequencer, not whether leader election runs at all).
+        let mut schedule = config.seq_da_addresses.clone();
+        schedule.sort_by_key(|a| a.to_string());
+        self.sequencer_schedule.set(&schedule, working_set);
+
+        if config.is_preferred_sequencer {
+            if let Some(preferred) = config.seq_da_addresses.first() {
+                self.preferred_sequencer.set(preferred, working_set);
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,65 @@
+use sov_modules_api::hooks::{ApplyBlobHooks, SlotHooks};
+use sov_modules_api::{Context, DaSpec, WorkingSet};
+
+use crate::SequencerRegistry;
+
+impl<C: Context, Da: DaSpec> SlotHooks<Da> for SequencerRegistry<C, Da> {
+    type Context = C;
+
+    /// Records the slot height being applied, so `begin_blob_hook` (which
+    /// isn't itself passed the slot) can look up who's leader for it.
+    fn begin_slot_hook(
+        &self,
+        slot_height: u64,
+        _pre_state_root: &<Da as sov_modules_api::DaSpec>::SlotHash,
+        working_set: &mut WorkingSet<C>,
+    ) {
+        self.current_slot.set(&slot_height, working_set);
+    }
+
+    fn end_slot_hook(&self, _working_set: &mut WorkingSet<C>) {}
+}
+
+impl<C: Context, Da: DaSpec> ApplyBlobHooks<Da::BlobTransaction> for SequencerRegistry<C, Da> {
+    type Context = C;
+
+    /// Rejects blobs from sequencers that aren't registered at all.
+    /// With a schedule of exactly one sequencer this is the full check
+    /// (the previous single-preferred-sequencer behavior: any registered
+    /// sequencer may submit). With more than one registered sequencer, also
+    /// rejects a blob from a sequencer that isn't this slot's leader, so
+    /// only the elected leader's batch is applied for a given slot.
+    fn begin_blob_hook(
+        &self,
+        blob: &mut Da::BlobTransaction,
+        working_set: &mut WorkingSet<C>,
+    ) -> anyhow::Result<()> {
+        use sov_rollup_interface::da::BlobReaderTrait;
+
+        let sender = blob.sender();
+        if !self.is_sender_allowed(&sender, working_set) {
+            anyhow::bail!("blob sender {} is not a registered sequencer", sender);
+        }
+
+        let schedule_len = self
+            .sequencer_schedule
+            .get(working_set)
+            .map(|schedule| schedule.len())
+            .unwrap_or(0);
+
+        if schedule_len > 1 {
+            let slot = self.current_slot.get(working_set).unwrap_or(0);
+            if !self.is_leader_at(&sender, slot, working_set) {
+                anyhow::bail!(
+                    "blob sender {sender} is not the leader for slot {slot}; rejecting out of turn"
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    fn end_blob_hook(&self, _working_set: &mut WorkingSet<C>) -> anyhow::Result<()> {
+        Ok(())
+    }
+}
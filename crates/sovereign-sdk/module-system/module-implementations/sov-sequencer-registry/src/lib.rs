@@ -3,7 +3,8 @@
 //! sequencer is supported. The sequencer's address and bond are registered
 //! during the rollup deployment.
 //!
-//! The module implements the [`sov_modules_api::hooks::ApplyBlobHooks`] trait.
+//! The module implements the [`sov_modules_api::hooks::ApplyBlobHooks`] and
+//! [`sov_modules_api::hooks::SlotHooks`] traits.
 
 #![deny(missing_docs)]
 mod call;
@@ -37,12 +38,29 @@ pub struct SequencerRegistry<C: sov_modules_api::Context, Da: sov_modules_api::D
     #[state]
     pub(crate) allowed_sequencers: StateMap<Da::Address, C::Address, BcsCodec>,
 
-    /// Optional preferred sequencer.
-    /// If set, batches from this sequencer will be processed first in block,
-    /// So this sequencer can guarantee soft confirmation time for transactions
+    /// The ordered, rotating set of sequencers allowed to produce soft
+    /// confirmations. The sequencer at `schedule[slot % schedule.len()]` is
+    /// the leader for `slot`. A schedule of length one reproduces the
+    /// previous single-preferred-sequencer behavior.
+    #[state]
+    pub(crate) sequencer_schedule: StateValue<Vec<Da::Address>, BcsCodec>,
+
+    /// The designated preferred sequencer, set at genesis from
+    /// [`SequencerConfig::seq_da_addresses`]`[0]` when
+    /// [`SequencerConfig::is_preferred_sequencer`] is `true`. Deliberately
+    /// tracked separately from `sequencer_schedule`, which gets re-sorted on
+    /// every registration/exit and so cannot be relied on to preserve which
+    /// address was originally preferred.
     #[state]
     pub(crate) preferred_sequencer: StateValue<Da::Address, BcsCodec>,
 
+    /// The DA/rollup height of the slot currently being applied, set by
+    /// [`sov_modules_api::hooks::SlotHooks::begin_slot_hook`] since
+    /// [`sov_modules_api::hooks::ApplyBlobHooks::begin_blob_hook`] isn't
+    /// itself passed the slot height.
+    #[state]
+    pub(crate) current_slot: StateValue<u64>,
+
     /// Coin's that will be slashed if the sequencer is malicious.
     /// The coins will be transferred from
     /// [`SequencerConfig::seq_rollup_address`] to
@@ -128,10 +146,79 @@ impl<C: sov_modules_api::Context, Da: sov_modules_api::DaSpec> SequencerRegistry
         self.allowed_sequencers
             .set(da_address, rollup_address, working_set);
 
+        self.insert_into_schedule(da_address, working_set);
+
         Ok(())
     }
 
-    /// Returns the preferred sequencer, or [`None`] it wasn't set.
+    pub(crate) fn exit_sequencer(
+        &self,
+        da_address: &Da::Address,
+        working_set: &mut WorkingSet<C>,
+    ) -> anyhow::Result<()> {
+        let rollup_address = self
+            .allowed_sequencers
+            .get(da_address, working_set)
+            .ok_or_else(|| anyhow::anyhow!("sequencer {} is not registered", da_address))?;
+
+        let locker = &self.address;
+        let coins = self.coins_to_lock.get_or_err(working_set)?;
+        self.bank
+            .transfer_from(locker, &rollup_address, coins, working_set)?;
+
+        self.allowed_sequencers.delete(da_address, working_set);
+        self.remove_from_schedule(da_address, working_set);
+
+        Ok(())
+    }
+
+    /// Inserts `da_address` into the rotation schedule and re-sorts it so
+    /// the schedule stays identical across nodes applying the same blobs,
+    /// regardless of insertion order.
+    fn insert_into_schedule(&self, da_address: &Da::Address, working_set: &mut WorkingSet<C>) {
+        let mut schedule = self.sequencer_schedule.get(working_set).unwrap_or_default();
+        if !schedule.iter().any(|a| a == da_address) {
+            schedule.push(da_address.clone());
+            schedule.sort_by_key(|a| a.to_string());
+        }
+        self.sequencer_schedule.set(&schedule, working_set);
+    }
+
+    /// Removes `da_address` from the rotation schedule, preserving the
+    /// deterministic ordering of the remaining entries.
+    fn remove_from_schedule(&self, da_address: &Da::Address, working_set: &mut WorkingSet<C>) {
+        let mut schedule = self.sequencer_schedule.get(working_set).unwrap_or_default();
+        schedule.retain(|a| a != da_address);
+        self.sequencer_schedule.set(&schedule, working_set);
+    }
+
+    /// Returns the leader for `slot` (the DA/rollup height the blob is
+    /// applied at), i.e. `schedule[slot % schedule.len()]`, or [`None`] if
+    /// the schedule is empty.
+    pub fn current_leader(&self, slot: u64, working_set: &mut WorkingSet<C>) -> Option<Da::Address> {
+        let schedule = self.sequencer_schedule.get(working_set)?;
+        if schedule.is_empty() {
+            return None;
+        }
+        schedule.get((slot as usize) % schedule.len()).cloned()
+    }
+
+    /// Returns `true` if `sender` is the leader for `slot`. With a schedule
+    /// of length one this is equivalent to the old "is preferred sequencer"
+    /// check; with more than one registered sequencer it implements
+    /// round-robin leader election across DA/rollup height.
+    pub fn is_leader_at(
+        &self,
+        sender: &Da::Address,
+        slot: u64,
+        working_set: &mut WorkingSet<C>,
+    ) -> bool {
+        self.current_leader(slot, working_set)
+            .as_ref()
+            .is_some_and(|leader| leader == sender)
+    }
+
+    /// Returns the preferred sequencer, or [`None`] if none was configured.
     ///
     /// Read about [`SequencerConfig::is_preferred_sequencer`] to learn about
     /// preferred sequencers.
@@ -147,7 +234,7 @@ impl<C: sov_modules_api::Context, Da: sov_modules_api::DaSpec> SequencerRegistry
         &self,
         working_set: &mut WorkingSet<C>,
     ) -> Option<C::Address> {
-        self.preferred_sequencer.get(working_set).map(|da_addr| {
+        self.get_preferred_sequencer(working_set).map(|da_addr| {
             self.allowed_sequencers
                 .get(&da_addr, working_set)
                 .expect("Preferred Sequencer must have known address on rollup")